@@ -0,0 +1,153 @@
+use std::io;
+
+use crate::{get_valid_words, simulate_guess, solve, LetterResult};
+
+/// Parse an encoded feedback string where each character is `g` (correct letter, correct
+/// place), `y` (correct letter, wrong place) or `.` (not in either answer).
+pub fn parse_feedback(encoded: &str) -> Result<Vec<LetterResult>, String> {
+    encoded
+        .chars()
+        .map(|c| match c {
+            'g' | 'G' => Ok(LetterResult::CorrectLetterCorrectPlace),
+            'y' | 'Y' => Ok(LetterResult::CorrectLetterWrongPlace),
+            '.' => Ok(LetterResult::WrongLetter),
+            other => Err(format!(
+                "unrecognised feedback character '{other}' (expected 'g', 'y' or '.')"
+            )),
+        })
+        .collect()
+}
+
+/// Tracks the set of answer-pairs still consistent with feedback from a QWordle being played
+/// outside this crate (e.g. in a browser), so the crate can be used as an external solver.
+pub struct AssistState {
+    candidates: Vec<(String, String)>,
+    valid_words: Vec<String>,
+}
+
+impl AssistState {
+    /// Start with every disjoint-letter pair from the word bank as a candidate answer.
+    pub fn new() -> Self {
+        AssistState {
+            candidates: solve::builtin_candidate_pairs().clone(),
+            valid_words: get_valid_words(),
+        }
+    }
+
+    /// Number of answer-pairs still consistent with every guess recorded so far.
+    pub fn candidate_count(&self) -> usize {
+        self.candidates.len()
+    }
+
+    /// Record that `word` was guessed and produced `encoded` feedback (see `parse_feedback`),
+    /// narrowing the candidate set accordingly.
+    pub fn record_guess(&mut self, word: &str, encoded: &str) -> Result<(), String> {
+        let feedback = parse_feedback(encoded)?;
+        if feedback.len() != word.len() {
+            return Err(format!(
+                "feedback length {} does not match word length {}",
+                feedback.len(),
+                word.len()
+            ));
+        }
+
+        self.candidates
+            .retain(|(a, b)| simulate_guess(word, &[a.clone(), b.clone()]).letter_results == feedback);
+
+        Ok(())
+    }
+
+    /// Suggest the next guess, maximising expected information about the remaining candidates.
+    pub fn suggest_guess(&self) -> String {
+        solve::best_entropy_guess(&self.valid_words, &self.candidates)
+    }
+}
+
+impl Default for AssistState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Interactive REPL for helping with a QWordle being played elsewhere: enter each guess as
+/// `<word> <feedback>` (e.g. `arise gy..g`) and see how many candidate pairs remain and what
+/// to guess next. An empty line exits.
+pub fn assist() {
+    let mut state = AssistState::new();
+
+    println!("QWordle assist mode. Enter each guess as '<word> <feedback>', e.g. 'arise gy..g'.");
+
+    loop {
+        println!(
+            "{} candidate pairs remain. Suggested guess: {}",
+            state.candidate_count(),
+            state.suggest_guess()
+        );
+
+        let mut buffer = String::new();
+        io::stdin().read_line(&mut buffer).unwrap();
+        let buffer = buffer.trim();
+        if buffer.is_empty() {
+            break;
+        }
+
+        let mut parts = buffer.split_whitespace();
+        let (word, feedback) = match (parts.next(), parts.next()) {
+            (Some(word), Some(feedback)) => (word.to_ascii_lowercase(), feedback.to_string()),
+            _ => {
+                println!("Expected '<word> <feedback>', e.g. 'arise gy..g'");
+                continue;
+            }
+        };
+
+        if let Err(e) = state.record_guess(&word, &feedback) {
+            println!("{e}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EncodedRenderer, Render};
+
+    #[test]
+    fn test_parse_feedback_happy_path() {
+        let feedback = parse_feedback("gy.").unwrap();
+        assert_eq!(
+            vec![
+                LetterResult::CorrectLetterCorrectPlace,
+                LetterResult::CorrectLetterWrongPlace,
+                LetterResult::WrongLetter,
+            ],
+            feedback
+        );
+    }
+
+    #[test]
+    fn test_parse_feedback_rejects_unrecognised_char() {
+        let err = parse_feedback("gx.").unwrap_err();
+        assert!(err.contains('x'), "{err}");
+    }
+
+    #[test]
+    fn test_record_guess_narrows_candidates_and_keeps_true_pair() {
+        let answers = vec!["arise".to_string(), "count".to_string()];
+        let mut state = AssistState::new();
+        let before = state.candidate_count();
+
+        let result = simulate_guess("shunt", &answers);
+        let feedback = EncodedRenderer.render(&result);
+        state.record_guess("shunt", &feedback).unwrap();
+
+        assert!(state.candidate_count() < before);
+        assert!(state.candidates.contains(&("arise".to_string(), "count".to_string())));
+    }
+
+    #[test]
+    fn test_record_guess_rejects_length_mismatch() {
+        let mut state = AssistState::new();
+        let err = state.record_guess("arise", "gy.").unwrap_err();
+        assert!(err.contains("does not match"), "{err}");
+    }
+}
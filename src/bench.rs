@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use rayon::prelude::*;
+
+use crate::{GameState, Solver};
+
+/// Aggregate statistics from running a solver over many games.
+pub struct BenchReport {
+    games_played: u32,
+    wins: u32,
+    /// Histogram of guesses taken, for games that were won.
+    guesses_histogram: HashMap<u8, u32>,
+}
+
+impl BenchReport {
+    fn empty() -> Self {
+        BenchReport {
+            games_played: 0,
+            wins: 0,
+            guesses_histogram: HashMap::new(),
+        }
+    }
+
+    fn merge(&mut self, other: BenchReport) {
+        self.games_played += other.games_played;
+        self.wins += other.wins;
+        for (guesses, count) in other.guesses_histogram {
+            *self.guesses_histogram.entry(guesses).or_insert(0) += count;
+        }
+    }
+
+    /// Percentage of games won, in `0.0..=100.0`.
+    pub fn win_percentage(&self) -> f64 {
+        if self.games_played == 0 {
+            return 0.0;
+        }
+        100.0 * self.wins as f64 / self.games_played as f64
+    }
+
+    /// Average number of guesses taken among games that were won.
+    pub fn average_winning_guesses(&self) -> f64 {
+        if self.wins == 0 {
+            return 0.0;
+        }
+        let total_guesses: u32 = self
+            .guesses_histogram
+            .iter()
+            .map(|(guesses, count)| *guesses as u32 * count)
+            .sum();
+        total_guesses as f64 / self.wins as f64
+    }
+}
+
+impl fmt::Display for BenchReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{}/{} games won ({:.1}%), average {:.2} guesses to win",
+            self.wins,
+            self.games_played,
+            self.win_percentage(),
+            self.average_winning_guesses()
+        )?;
+
+        let mut guess_counts: Vec<&u8> = self.guesses_histogram.keys().collect();
+        guess_counts.sort();
+        for guesses in guess_counts {
+            writeln!(f, "  {guesses}: {}", self.guesses_histogram[guesses])?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Run `n` randomly generated games of `max_guesses`, each played to completion by a fresh
+/// solver from `solver_factory`, and return aggregate win/guess statistics.
+///
+/// Games are split across `threads` rayon threads and the per-thread tallies are merged, since
+/// each game is independent. Pass `threads = 1` to run sequentially.
+pub fn run_bench<F, S>(n: u32, max_guesses: u8, threads: usize, solver_factory: F) -> BenchReport
+where
+    F: Fn() -> S + Sync,
+    S: Solver,
+{
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .expect("failed to build rayon thread pool");
+
+    pool.install(|| {
+        (0..n)
+            .into_par_iter()
+            .map(|_| play_one_game(max_guesses, &solver_factory))
+            .reduce(BenchReport::empty, |mut acc, report| {
+                acc.merge(report);
+                acc
+            })
+    })
+}
+
+fn play_one_game<F, S>(max_guesses: u8, solver_factory: &F) -> BenchReport
+where
+    F: Fn() -> S,
+    S: Solver,
+{
+    let mut state = GameState::new(max_guesses);
+    let mut solver = solver_factory();
+
+    let mut guesses_taken: u8 = 0;
+    let mut won = false;
+
+    while !state.out_of_guesses() {
+        solver.make_a_move(&mut state);
+        guesses_taken += 1;
+        if state.is_won() {
+            won = true;
+            break;
+        }
+    }
+
+    let mut guesses_histogram = HashMap::new();
+    if won {
+        guesses_histogram.insert(guesses_taken, 1);
+    }
+
+    BenchReport {
+        games_played: 1,
+        wins: if won { 1 } else { 0 },
+        guesses_histogram,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConstraintFilterSolver;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    fn test_run_bench_reports_games_played_and_merges_across_threads(#[case] threads: usize) {
+        let report = run_bench(4, 6, threads, ConstraintFilterSolver::new);
+
+        assert_eq!(4, report.games_played);
+        assert!(report.wins <= report.games_played);
+        assert!((0.0..=100.0).contains(&report.win_percentage()));
+        assert!(report.average_winning_guesses() >= 0.0);
+
+        let games_in_histogram: u32 = report.guesses_histogram.values().sum();
+        assert_eq!(report.wins, games_in_histogram);
+    }
+}
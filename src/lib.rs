@@ -2,26 +2,41 @@ use rand::seq::SliceRandom;
 use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::io;
+use std::path::PathBuf;
+
+mod assist;
+mod bench;
+mod render;
+mod solve;
+
+pub use assist::{assist, parse_feedback, AssistState};
+pub use bench::{run_bench, BenchReport};
+pub use render::{AnsiRenderer, EncodedRenderer, Render};
+pub use solve::{ConstraintFilterSolver, Solver};
 
 /// Struct showing the result for an individual letter.
-#[derive(Debug, PartialEq, Eq)]
-enum LetterResult {
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum LetterResult {
     CorrectLetterCorrectPlace,
     CorrectLetterWrongPlace,
     WrongLetter,
 }
 
 /// Struct showing the result for a guessed word.
-struct GuessResult {
+#[derive(Clone)]
+pub struct GuessResult {
     word: String,
     letter_results: Vec<LetterResult>,
+    /// Whether `word` contributed a letter to every answer, not just some of them. Named for
+    /// the original two-answer game; with a `GameBuilder`-configured `num_answers` above two it
+    /// means "found in all of the answers", not literally "both".
     both_words: bool,
 }
 
 /// Struct showing the result for a guessed word in a user-friendly way.
-struct ProcessedGuessResult {
-    is_correct: bool,
-    message: String,
+pub struct ProcessedGuessResult {
+    pub is_correct: bool,
+    pub message: String,
 }
 
 /// Filter words with repeated letters out of "data/word-bank.txt" and write to file "showing the result for a guessed word."
@@ -29,7 +44,7 @@ pub fn write_unique_words() {
     let contents = fs::read_to_string("data/word-bank.txt").unwrap();
     let words: Vec<&str> = contents
         .lines()
-        .filter(|line| None == check_repeated_letters(line))
+        .filter(|line| check_repeated_letters(line).is_none())
         .collect();
     let text = words.join("\n");
     let _ = fs::write("data/word-bank-unique.txt", text);
@@ -55,11 +70,9 @@ fn check_repeated_letters(word: &str) -> Option<HashMap<char, Vec<u8>>> {
             match letter_pos.get_mut(&letter) {
                 None => {
                     letter_pos.insert(letter, vec![idx]);
-                    ()
                 }
                 Some(v) => {
                     v.push(idx);
-                    ()
                 }
             }
         }
@@ -81,35 +94,228 @@ fn get_valid_words() -> Vec<String> {
     contents.split_whitespace().map(str::to_string).collect()
 }
 
-/// Pick two words (with no overlapping letters) from word bank.
-/// 
+/// Pick `count` words (with no overlapping letters between any of them) from `word_bank`.
+///
 /// # Arguments
-/// 
+///
+/// * `word_bank` - Candidate words to choose from (each already free of repeated letters).
+/// * `count` - How many mutually letter-disjoint words to pick.
 /// * `max_iterations` - Maximum number of attempts to find words that don't share letters.
-fn select_words(max_iterations: u8) -> Vec<String> {
-    let word_bank = get_word_bank();
+fn select_words(word_bank: &[String], count: u8, max_iterations: u8) -> Result<Vec<String>, String> {
+    if word_bank.len() < count as usize {
+        return Err(format!(
+            "word bank has only {} words, need at least {count}",
+            word_bank.len()
+        ));
+    }
 
-    let mut count = 0;
-    let words = loop {
-        if count >= max_iterations {
-            panic!("Could not find non-overlapping words in {} iterations", max_iterations)
+    let mut iteration = 0;
+    loop {
+        if iteration >= max_iterations {
+            return Err(format!(
+                "Could not find {count} non-overlapping words in {max_iterations} iterations"
+            ));
         }
 
-        let words: Vec<_> = word_bank
-            .choose_multiple(&mut rand::thread_rng(), 2)
+        let words: Vec<String> = word_bank
+            .choose_multiple(&mut rand::thread_rng(), count as usize)
+            .cloned()
             .collect();
-        let join_words = format!("{}{}", words[0], words[1]);
-        match check_repeated_letters(&join_words) {
-            None => break words,
-            Some(_) => count += 1,
+        let joined = words.concat();
+        match check_repeated_letters(&joined) {
+            None => return Ok(words),
+            Some(_) => iteration += 1,
         }
-    };
+    }
+}
+
+/// Which bundled word list a builtin [`WordSource`] should use.
+pub enum Language {
+    English,
+}
+
+/// Where a [`GameBuilder`] should load its word bank (candidate answers) and valid-word list
+/// from.
+pub enum WordSource {
+    /// Word lists bundled into the binary at compile time.
+    Builtin(Language),
+    /// Word lists loaded from disk at build-time, one word per line.
+    Files {
+        word_bank: PathBuf,
+        valid_words: PathBuf,
+    },
+}
+
+impl Default for WordSource {
+    fn default() -> Self {
+        WordSource::Builtin(Language::English)
+    }
+}
+
+fn read_word_list(path: &PathBuf) -> Result<Vec<String>, String> {
+    fs::read_to_string(path)
+        .map(|contents| contents.split_whitespace().map(str::to_string).collect())
+        .map_err(|e| format!("could not read word list {}: {e}", path.display()))
+}
 
-    vec![words[0].clone(), words[1].clone()]
+fn load_word_bank(source: &WordSource) -> Result<Vec<String>, String> {
+    match source {
+        WordSource::Builtin(Language::English) => Ok(get_word_bank()),
+        WordSource::Files { word_bank, .. } => read_word_list(word_bank),
+    }
+}
+
+fn load_valid_words(source: &WordSource) -> Result<Vec<String>, String> {
+    match source {
+        WordSource::Builtin(Language::English) => Ok(get_valid_words()),
+        WordSource::Files { valid_words, .. } => read_word_list(valid_words),
+    }
+}
+
+/// Builds a [`GameState`] with a configurable word length, number of hidden answers, maximum
+/// guesses, and word list source, instead of the fixed two 5-letter-word embedded-bank game.
+pub struct GameBuilder {
+    word_length: usize,
+    num_answers: u8,
+    max_guesses: u8,
+    source: WordSource,
+}
+
+impl GameBuilder {
+    /// Start from the classic QWordle defaults: two 5-letter answers, 6 guesses, builtin
+    /// English word lists.
+    pub fn new() -> Self {
+        GameBuilder {
+            word_length: 5,
+            num_answers: 2,
+            max_guesses: 6,
+            source: WordSource::default(),
+        }
+    }
+
+    pub fn word_length(mut self, word_length: usize) -> Self {
+        self.word_length = word_length;
+        self
+    }
+
+    pub fn num_answers(mut self, num_answers: u8) -> Self {
+        self.num_answers = num_answers;
+        self
+    }
+
+    pub fn max_guesses(mut self, max_guesses: u8) -> Self {
+        self.max_guesses = max_guesses;
+        self
+    }
+
+    pub fn word_source(mut self, source: WordSource) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Build the `GameState`, selecting `num_answers` mutually letter-disjoint words of
+    /// `word_length` from the configured word bank.
+    pub fn build(self) -> Result<GameState, String> {
+        let word_length = self.word_length;
+        let raw_word_bank = load_word_bank(&self.source)?;
+        let word_bank: Vec<String> = raw_word_bank
+            .iter()
+            .filter(|w| w.chars().count() == word_length)
+            .cloned()
+            .collect();
+        let valid_words: Vec<String> = load_valid_words(&self.source)?
+            .into_iter()
+            .filter(|w| w.chars().count() == word_length)
+            .collect();
+
+        let answers = select_words(&word_bank, self.num_answers, 100)?;
+
+        // Reuse the cached builtin candidate pairs when the word-length filter above didn't
+        // actually remove anything from the builtin bank, since candidate_pairs is O(n²) and
+        // this is by far the common case (GameState::new and the classic defaults).
+        let is_builtin_unfiltered =
+            matches!(self.source, WordSource::Builtin(Language::English)) && word_bank.len() == raw_word_bank.len();
+        let candidates = if is_builtin_unfiltered {
+            solve::builtin_candidate_pairs().clone()
+        } else {
+            solve::candidate_pairs(&word_bank)
+        };
+
+        Ok(GameState {
+            answers,
+            valid_words,
+            guess_count: 0,
+            max_guesses: self.max_guesses,
+            all_chars: "qwertyuiopasdfghjklzxcvbnm".chars().collect(),
+            found_chars: HashSet::new(),
+            eliminated_chars: HashSet::new(),
+            candidates,
+            word_bank,
+            history: Vec::new(),
+        })
+    }
+}
+
+impl Default for GameBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Compute the `GuessResult` for `guess` against a fixed set of `answers`, without touching
+/// any game state.
+///
+/// This is the same letter-by-letter logic `GameState::check_guess` applies to the real
+/// answers, but it is pure, so it can also be used to re-simulate a guess against *candidate*
+/// answer pairs (e.g. by a [`Solver`]).
+fn simulate_guess(guess: &str, answers: &[String]) -> GuessResult {
+    let mut letter_results: Vec<LetterResult> = Vec::new();
+    let mut guessed_in_answers = vec![false; answers.len()];
+    let mut first_result_for_letter: HashMap<char, LetterResult> = HashMap::new();
+
+    let repeated_guess_letters = check_repeated_letters(guess);
+
+    for (i, letter) in guess.chars().enumerate() {
+        let idx = i as u8;
+        // if there's a repeated letter in guess, only check the first occurrence against the
+        // answers (because we know there aren't repeated letters in the answers themselves);
+        // later occurrences just repeat that first result, so `letter_results` always has one
+        // entry per letter of `guess`, aligned with its positions.
+        let is_first_occurrence = match repeated_guess_letters.as_ref() {
+            Some(map) => map.get(&letter).unwrap().first().unwrap() == &idx,
+            None => true,
+        };
+
+        let result = if is_first_occurrence {
+            let result = if let Some(a_idx) = answers.iter().position(|a| a.chars().nth(i) == Some(letter)) {
+                guessed_in_answers[a_idx] = true;
+                LetterResult::CorrectLetterCorrectPlace
+            } else if let Some(a_idx) = answers.iter().position(|a| a.contains(letter)) {
+                guessed_in_answers[a_idx] = true;
+                LetterResult::CorrectLetterWrongPlace
+            } else {
+                LetterResult::WrongLetter
+            };
+            first_result_for_letter.insert(letter, result.clone());
+            result
+        } else {
+            first_result_for_letter.get(&letter).unwrap().clone()
+        };
+
+        letter_results.push(result);
+    }
+
+    let both_words = guessed_in_answers.iter().all(|&b| b);
+
+    GuessResult {
+        word: guess.to_string(),
+        letter_results,
+        both_words,
+    }
 }
 
 /// Struct holding the game state whilst in operation.
-struct GameState {
+pub struct GameState {
     answers: Vec<String>,
     valid_words: Vec<String>,
     guess_count: u8,
@@ -117,28 +323,29 @@ struct GameState {
     all_chars: HashSet<char>,
     found_chars: HashSet<char>,
     eliminated_chars: HashSet<char>,
+    /// Answer-pairs from the word bank still consistent with every guess made so far; used by
+    /// `suggest_guess` to score candidate guesses without needing to know the real answers.
+    candidates: Vec<(String, String)>,
+    /// The word bank this game's answers were drawn from, kept so `undo` can rebuild
+    /// `candidates` from scratch.
+    word_bank: Vec<String>,
+    /// Every guess made so far, in order, so that `undo` and `replay` can reconstruct state.
+    history: Vec<GuessResult>,
 }
 
 impl GameState {
 
-    /// Create new GameState
-    /// 
+    /// Create a new GameState with the classic QWordle defaults (two 5-letter answers, builtin
+    /// English word lists). Use [`GameBuilder`] for other configurations.
+    ///
     /// # Arguments
-    /// 
+    ///
     /// * `max_guesses` - The maximum number of guesses a user is allowed.
     pub fn new(max_guesses: u8) -> GameState {
-        let valid_words = get_valid_words();
-        let answers = select_words(100);
-
-        GameState {
-            answers: answers,
-            valid_words: valid_words,
-            guess_count: 0,
-            max_guesses: max_guesses,
-            all_chars: "qwertyuiopasdfghjklzxcvbnm".chars().collect(),
-            found_chars: HashSet::new(),
-            eliminated_chars: HashSet::new(),
-        }
+        GameBuilder::new()
+            .max_guesses(max_guesses)
+            .build()
+            .expect("builtin English word lists should always yield two disjoint 5-letter words")
     }
 
     /// Guess an answer, returning `GuessResult` (or Error if `guess` is not a valid word.)
@@ -147,8 +354,8 @@ impl GameState {
     /// 
     /// * `guess` - The user's guess
     pub fn guess(&mut self, guess: &String) -> Result<GuessResult, String> {
-        if self.validate_guess(guess) == false {
-            let s = format!("Not a valid word! Please guess again");
+        if !self.validate_guess(guess) {
+            let s = "Not a valid word! Please guess again".to_string();
             return Err(s);
         }
 
@@ -183,11 +390,12 @@ impl GameState {
 
     /// Return user-friendly string detailing the answers.
     fn answers_string(&self) -> String {
-        format!(
-            "The answers were {} and {}",
-            self.answers[0].to_ascii_uppercase(),
-            self.answers[1].to_ascii_uppercase(),
-        )
+        let answers_upper: Vec<String> = self
+            .answers
+            .iter()
+            .map(|answer| answer.to_ascii_uppercase())
+            .collect();
+        format!("The answers were {}", answers_upper.join(" and "))
     }
 
     pub fn get_found_letters(&self) -> &HashSet<char> {
@@ -208,92 +416,92 @@ impl GameState {
     }
 
     /// Return `GuessResult` for `guess`, detailing the result for each letter in `guess` and whether this represents both answers.
-    fn check_guess(&mut self, guess: &String) -> GuessResult {
-        let mut letter_results = Vec::new();
-        let mut guessed_in_answers = vec![false, false];
+    fn check_guess(&mut self, guess: &str) -> GuessResult {
+        let result = simulate_guess(guess, &self.answers);
 
-        let repeated_guess_letters = check_repeated_letters(&guess);
+        self.update_found_and_eliminated(guess);
+        solve::filter_consistent(&mut self.candidates, guess, &result);
+        self.history.push(result.clone());
 
-        for (i, letter) in guess.chars().enumerate() {
-            let idx = i as u8;
-            if let Some(_) = repeated_guess_letters {
-                // if there's a repeated letter in guess, only get info about the first occurrence
-                // (because we know there aren't repeated letters in the answers)
-                let map = repeated_guess_letters.as_ref().unwrap();
-                let indices = map.get(&letter).unwrap();
-                if indices.first().unwrap() != &idx {
-                    // if we're past the first occurrence, go to next letter in for loop
-                    continue;
-                }
-            }
+        result
+    }
 
-            let result = if letter == self.answers[0].chars().nth(i).unwrap() {
-                guessed_in_answers[0] = true;
-                LetterResult::CorrectLetterCorrectPlace
-            } else if letter == self.answers[1].chars().nth(i).unwrap() {
-                guessed_in_answers[1] = true;
-                LetterResult::CorrectLetterCorrectPlace
-            } else if self.answers[0].contains(letter) {
-                guessed_in_answers[0] = true;
-                LetterResult::CorrectLetterWrongPlace
-            } else if self.answers[1].contains(letter) {
-                guessed_in_answers[1] = true;
-                LetterResult::CorrectLetterWrongPlace
+    /// Mark every letter of `guess` as found or eliminated, depending on whether it appears in
+    /// any answer.
+    fn update_found_and_eliminated(&mut self, guess: &str) {
+        for letter in guess.chars() {
+            if self.answers.iter().any(|answer| answer.contains(letter)) {
+                self.found_chars.insert(letter);
             } else {
                 self.eliminated_chars.insert(letter);
-                LetterResult::WrongLetter
-            };
-
-            letter_results.push(result);
+            }
         }
+    }
 
-        let both_words = guessed_in_answers.iter().all(|&b| b);
+    /// Suggest the next guess to play, maximising expected information about the answer pair.
+    pub fn suggest_guess(&self) -> String {
+        solve::best_entropy_guess(&self.valid_words, &self.candidates)
+    }
 
-        GuessResult {
-            word: guess.clone(),
-            letter_results: letter_results,
-            both_words: both_words,
+    /// Roll back the last `n` guesses. `found_chars`, `eliminated_chars` and `candidates` are
+    /// recomputed from scratch by replaying the remaining history, since an eliminated or found
+    /// letter can't simply be un-marked without knowing whether an earlier guess also revealed it.
+    pub fn undo(&mut self, n: u8) {
+        let keep = self.history.len().saturating_sub(n as usize);
+        self.history.truncate(keep);
+        self.guess_count = self.history.len() as u8;
+
+        self.found_chars.clear();
+        self.eliminated_chars.clear();
+        self.candidates = solve::candidate_pairs(&self.word_bank);
+
+        for result in self.history.clone() {
+            self.update_found_and_eliminated(&result.word);
+            solve::filter_consistent(&mut self.candidates, &result.word, &result);
         }
     }
 
-    /// Return `ProcessedGuessResult`, with whether `guess` was correct and a message to display to the user.
-    pub fn process_guess(&mut self, guess_result: &GuessResult) -> ProcessedGuessResult {
-        let mut format_guess_check = String::new();
-        // let guess_result = self.check_guess(&guess);
-
-        let guess = &guess_result.word;
-
-        for (i, letter_result) in guess_result.letter_results.iter().enumerate() {
-            let letter_upper = guess.chars().nth(i).unwrap().to_ascii_uppercase();
-
-            let append_char = match letter_result {
-                LetterResult::CorrectLetterCorrectPlace => {
-                    format!("\x1b[92m{letter_upper}\x1b[0m")
-                }
-                LetterResult::CorrectLetterWrongPlace => {
-                    format!("\x1b[93m{letter_upper}\x1b[0m")
-                }
-                LetterResult::WrongLetter => {
-                    format!("{letter_upper}")
-                }
-            };
+    /// Return the full sequence of processed guesses made so far, in order, so a caller (e.g. a
+    /// REPL) can redraw the board after an `undo`.
+    pub fn replay(&mut self) -> Vec<ProcessedGuessResult> {
+        let history = self.history.clone();
+        history.iter().map(|result| self.process_guess(result)).collect()
+    }
 
-            format_guess_check += &append_char;
-        }
+    /// Return true if the most recent guess was one of the answers.
+    pub fn is_won(&self) -> bool {
+        self.history
+            .last()
+            .is_some_and(|result| self.answers.contains(&result.word))
+    }
 
-        let is_correct = self.answers.contains(guess);
+    /// Return `ProcessedGuessResult`, with whether `guess` was correct and a message to display to the user.
+    pub fn process_guess(&mut self, guess_result: &GuessResult) -> ProcessedGuessResult {
+        self.process_guess_with(guess_result, &render::AnsiRenderer)
+    }
 
-        if is_correct == false {
-            let s = if guess_result.both_words {
-                format!("  (both words)")
-            } else {
-                format!("  (same word)")
+    /// As `process_guess`, but render the guess with `renderer` instead of the default
+    /// ANSI-colored terminal text (e.g. `EncodedRenderer` for a TUI or web frontend).
+    pub fn process_guess_with(&mut self, guess_result: &GuessResult, renderer: &dyn Render) -> ProcessedGuessResult {
+        let mut format_guess_check = renderer.render(guess_result);
+
+        let is_correct = self.answers.contains(&guess_result.word);
+
+        if !is_correct {
+            // For the classic two-answer game keep the original wording; for a
+            // GameBuilder-configured num_answers above two, both_words means "found in all
+            // answers", so generalise the message to say how many that is.
+            let s = match self.answers.len() {
+                2 if guess_result.both_words => "  (both words)".to_string(),
+                2 => "  (same word)".to_string(),
+                n if guess_result.both_words => format!("  (all {n} words)"),
+                _ => "  (one word)".to_string(),
             };
             format_guess_check += &s;
         }
 
         ProcessedGuessResult {
-            is_correct: is_correct,
+            is_correct,
             message: format_guess_check,
         }
     }
@@ -363,11 +571,24 @@ mod tests {
 
     #[test]
     fn test_select_words() {
-        let words = select_words(100);
+        let word_bank = get_word_bank();
+        let words = select_words(&word_bank, 2, 100).unwrap();
         let all_chars: HashSet<char> = words.join("").chars().collect();
         assert_eq!(10, all_chars.len());
     }
 
+    #[test]
+    fn test_game_builder_word_length() {
+        let state = GameBuilder::new()
+            .word_length(5)
+            .num_answers(2)
+            .max_guesses(6)
+            .build()
+            .unwrap();
+        assert!(state.answers.iter().all(|answer| answer.chars().count() == 5));
+        assert_eq!(2, state.answers.len());
+    }
+
     #[rstest]
     #[case(vec![String::from("lymph"), String::from("audio"), String::from("sever")], vec![false, true, false], vec![false, false, false])]
     #[case(vec![String::from("shunt"), String::from("wrist"), String::from("arise")], vec![true, true, false], vec![false, false, true])]
@@ -378,23 +599,83 @@ mod tests {
         let answers: Vec<String> = vec![String::from("arise"), String::from("count")];
         let valid_words = get_valid_words();
         let mut state = GameState {
-            answers: answers,
-            valid_words: valid_words,
+            answers,
+            valid_words,
             guess_count: 0,
             max_guesses: 3,
             all_chars: "qwertyuiopasdfghjklzxcvbnm".chars().collect(),
             found_chars: HashSet::new(),
             eliminated_chars: HashSet::new(),
+            candidates: solve::candidate_pairs(&get_word_bank()),
+            word_bank: get_word_bank(),
+            history: Vec::new(),
         };
 
         for (i, guess) in guesses.iter().enumerate() {
-            let result = state.guess(&guess).unwrap();
+            let result = state.guess(guess).unwrap();
             let processed_result = state.process_guess(&result);
             assert_eq!(both_words[i], result.both_words, "guess '{}' expected both words={}, got {}", guess, both_words[i], result.both_words);
             assert_eq!(is_correct[i], processed_result.is_correct, "guess '{}' expected is correct={}, got {}", guess, is_correct[i], processed_result.is_correct);
         }
     }
 
-        
+    #[test]
+    fn test_process_guess_message_generalises_for_more_than_two_answers() {
+        let answers: Vec<String> = vec![String::from("adopt"), String::from("curve"), String::from("whisk")];
+        let valid_words = get_valid_words();
+        let mut state = GameState {
+            answers,
+            valid_words,
+            guess_count: 0,
+            max_guesses: 3,
+            all_chars: "qwertyuiopasdfghjklzxcvbnm".chars().collect(),
+            found_chars: HashSet::new(),
+            eliminated_chars: HashSet::new(),
+            candidates: solve::candidate_pairs(&get_word_bank()),
+            word_bank: get_word_bank(),
+            history: Vec::new(),
+        };
+
+        // "grasp" shares a letter with every answer.
+        let result = state.guess(&String::from("grasp")).unwrap();
+        let processed = state.process_guess(&result);
+        assert!(processed.message.contains("all 3 words"), "{}", processed.message);
 
+        // "sissy" shares a letter with only "whisk".
+        let result = state.guess(&String::from("sissy")).unwrap();
+        let processed = state.process_guess(&result);
+        assert!(processed.message.contains("one word"), "{}", processed.message);
+    }
+
+    #[test]
+    fn test_undo() {
+        let answers: Vec<String> = vec![String::from("arise"), String::from("count")];
+        let valid_words = get_valid_words();
+        let mut state = GameState {
+            answers,
+            valid_words,
+            guess_count: 0,
+            max_guesses: 3,
+            all_chars: "qwertyuiopasdfghjklzxcvbnm".chars().collect(),
+            found_chars: HashSet::new(),
+            eliminated_chars: HashSet::new(),
+            candidates: solve::candidate_pairs(&get_word_bank()),
+            word_bank: get_word_bank(),
+            history: Vec::new(),
+        };
+
+        state.guess(&String::from("shunt")).unwrap();
+        state.guess(&String::from("wrist")).unwrap();
+        assert_eq!(2, state.guess_count);
+        assert_eq!(2, state.replay().len());
+
+        state.undo(1);
+        assert_eq!(1, state.guess_count);
+        assert_eq!(1, state.replay().len());
+
+        state.undo(10);
+        assert_eq!(0, state.guess_count);
+        assert!(state.found_chars.is_empty());
+        assert!(state.eliminated_chars.is_empty());
+    }
 }
@@ -0,0 +1,108 @@
+use std::fmt;
+
+use crate::{GuessResult, LetterResult};
+
+/// Turns a `GuessResult` into user-facing output. Decouples game logic from any one
+/// presentation, so the same `GuessResult` can drive ANSI-colored terminal text, a TUI, a web
+/// frontend, or plain-text tests.
+pub trait Render {
+    fn render(&self, guess_result: &GuessResult) -> String;
+}
+
+/// Renders a guess as ANSI-colored terminal text: green for correct letter/correct place,
+/// yellow for correct letter/wrong place, uncolored for wrong.
+pub struct AnsiRenderer;
+
+impl Render for AnsiRenderer {
+    fn render(&self, guess_result: &GuessResult) -> String {
+        let mut rendered = String::new();
+
+        for (letter, letter_result) in guess_result.word.chars().zip(guess_result.letter_results.iter()) {
+            let letter_upper = letter.to_ascii_uppercase();
+
+            let append = match letter_result {
+                LetterResult::CorrectLetterCorrectPlace => format!("\x1b[92m{letter_upper}\x1b[0m"),
+                LetterResult::CorrectLetterWrongPlace => format!("\x1b[93m{letter_upper}\x1b[0m"),
+                LetterResult::WrongLetter => format!("{letter_upper}"),
+            };
+
+            rendered += &append;
+        }
+
+        rendered
+    }
+}
+
+/// Renders a guess as an uncolored encoded string, one character per letter: `g` for correct
+/// letter/correct place, `y` for correct letter/wrong place, `.` for wrong. The inverse of
+/// `crate::parse_feedback`.
+pub struct EncodedRenderer;
+
+impl Render for EncodedRenderer {
+    fn render(&self, guess_result: &GuessResult) -> String {
+        guess_result
+            .letter_results
+            .iter()
+            .map(|letter_result| match letter_result {
+                LetterResult::CorrectLetterCorrectPlace => 'g',
+                LetterResult::CorrectLetterWrongPlace => 'y',
+                LetterResult::WrongLetter => '.',
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for GuessResult {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", EncodedRenderer.render(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn guess_result(word: &str, letter_results: Vec<LetterResult>, both_words: bool) -> GuessResult {
+        GuessResult {
+            word: word.to_string(),
+            letter_results,
+            both_words,
+        }
+    }
+
+    #[rstest]
+    #[case("abc", vec![LetterResult::CorrectLetterCorrectPlace, LetterResult::CorrectLetterWrongPlace, LetterResult::WrongLetter], "gy.")]
+    #[case("ab", vec![LetterResult::WrongLetter, LetterResult::WrongLetter], "..")]
+    #[case("geese", vec![LetterResult::CorrectLetterCorrectPlace, LetterResult::CorrectLetterWrongPlace, LetterResult::WrongLetter, LetterResult::WrongLetter, LetterResult::CorrectLetterCorrectPlace], "gy..g")]
+    fn test_encoded_renderer(#[case] word: &str, #[case] letter_results: Vec<LetterResult>, #[case] expected: &str) {
+        let result = guess_result(word, letter_results, false);
+        assert_eq!(expected, EncodedRenderer.render(&result));
+        assert_eq!(expected, result.to_string());
+    }
+
+    #[test]
+    fn test_ansi_renderer_pairs_result_with_word_index() {
+        // "geese" has a repeated 'e'; each letter_result must line up with the word's own
+        // letters, not just its own position in a shorter list.
+        let result = guess_result(
+            "geese",
+            vec![
+                LetterResult::CorrectLetterCorrectPlace,
+                LetterResult::CorrectLetterWrongPlace,
+                LetterResult::WrongLetter,
+                LetterResult::WrongLetter,
+                LetterResult::CorrectLetterCorrectPlace,
+            ],
+            false,
+        );
+        let rendered = AnsiRenderer.render(&result);
+        assert!(rendered.contains('G'));
+        assert!(rendered.contains('E'));
+        assert!(rendered.contains('S'));
+        assert_eq!(
+            "\x1b[92mG\x1b[0m\x1b[93mE\x1b[0mES\x1b[92mE\x1b[0m",
+            rendered
+        );
+    }
+}
@@ -0,0 +1,282 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::OnceLock;
+
+use rand::seq::SliceRandom;
+
+use crate::{check_repeated_letters, get_valid_words, get_word_bank, simulate_guess, GameState, GuessResult, LetterResult};
+
+/// Feedback pattern a guess produces against a candidate pair: the per-letter results, plus
+/// whether it matched every answer.
+type PatternKey = (Vec<LetterResult>, bool);
+
+/// Candidate answer-pairs, partitioned by the `PatternKey` a guess would produce against each.
+type PatternBuckets = HashMap<PatternKey, Vec<(String, String)>>;
+
+/// Number of valid words randomly sampled for scoring per move. Scoring a guess is
+/// O(candidates), so scoring every valid word against every candidate pair on the full embedded
+/// bank makes a single move -- and so `run_bench` over many games -- impractically slow. Score a
+/// random sample instead, always including any candidate that's itself a valid word, since ties
+/// are broken in their favour.
+const MAX_GUESSES_SCORED: usize = 200;
+
+/// Every disjoint-letter pair from the builtin English word bank, computed once and shared by
+/// every `ConstraintFilterSolver`, `AssistState` and builtin-sourced `GameBuilder`, since
+/// `candidate_pairs` is O(n²) over the bank and would otherwise be rebuilt on every game/solver.
+pub(crate) fn builtin_candidate_pairs() -> &'static Vec<(String, String)> {
+    static PAIRS: OnceLock<Vec<(String, String)>> = OnceLock::new();
+    PAIRS.get_or_init(|| candidate_pairs(&get_word_bank()))
+}
+
+/// Something that can choose and play guesses against a `GameState` on its own.
+pub trait Solver {
+    /// Choose the next guess, play it against `state`, and return the word that was guessed.
+    fn make_a_move(&mut self, state: &mut GameState) -> String;
+}
+
+/// Baseline solver: keeps every answer-pair from the word bank that is still consistent with
+/// the feedback seen so far, and guesses whichever valid word eliminates the most of them on
+/// average.
+pub struct ConstraintFilterSolver {
+    candidates: Vec<(String, String)>,
+    valid_words: Vec<String>,
+}
+
+impl ConstraintFilterSolver {
+    /// Build a solver whose candidates are every disjoint-letter pair in the word bank.
+    pub fn new() -> Self {
+        ConstraintFilterSolver {
+            candidates: builtin_candidate_pairs().clone(),
+            valid_words: get_valid_words(),
+        }
+    }
+
+    /// Number of answer-pairs still consistent with every guess made so far.
+    pub fn candidate_count(&self) -> usize {
+        self.candidates.len()
+    }
+
+    fn choose_guess(&self) -> String {
+        let is_possible_answer = |w: &str| self.candidates.iter().any(|(a, b)| a == w || b == w);
+
+        guesses_to_score(&self.valid_words, &self.candidates)
+            .into_iter()
+            .min_by(|a, b| {
+                average_remaining(a, &self.candidates)
+                    .partial_cmp(&average_remaining(b, &self.candidates))
+                    .unwrap()
+                    .then_with(|| is_possible_answer(b).cmp(&is_possible_answer(a)))
+            })
+            .cloned()
+            .expect("valid words list should never be empty")
+    }
+
+    fn filter_candidates(&mut self, guess: &str, result: &GuessResult) {
+        filter_consistent(&mut self.candidates, guess, result);
+    }
+}
+
+impl Default for ConstraintFilterSolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Solver for ConstraintFilterSolver {
+    fn make_a_move(&mut self, state: &mut GameState) -> String {
+        let guess = self.choose_guess();
+        if let Ok(result) = state.guess(&guess) {
+            self.filter_candidates(&guess, &result);
+        }
+        guess
+    }
+}
+
+/// Discard every candidate pair whose simulated feedback for `guess` doesn't match `result`.
+pub(crate) fn filter_consistent(candidates: &mut Vec<(String, String)>, guess: &str, result: &GuessResult) {
+    candidates.retain(|(a, b)| {
+        let simulated = simulate_guess(guess, &[a.clone(), b.clone()]);
+        simulated.letter_results == result.letter_results && simulated.both_words == result.both_words
+    });
+}
+
+/// All pairs of words from `words` that share no letters, per `check_repeated_letters`.
+pub(crate) fn candidate_pairs(words: &[String]) -> Vec<(String, String)> {
+    let mut pairs = Vec::new();
+    for (i, a) in words.iter().enumerate() {
+        for b in &words[i + 1..] {
+            let joined = format!("{a}{b}");
+            if check_repeated_letters(&joined).is_none() {
+                pairs.push((a.clone(), b.clone()));
+            }
+        }
+    }
+    pairs
+}
+
+/// Bound the set of guesses scored for a move: a random sample of `MAX_GUESSES_SCORED` words
+/// from `valid_words`, plus any `candidates` entry that's also a valid word (there are at
+/// most a couple of these once the candidate set has narrowed, and scoring favours them anyway).
+fn guesses_to_score<'a>(valid_words: &'a [String], candidates: &[(String, String)]) -> Vec<&'a String> {
+    if valid_words.len() <= MAX_GUESSES_SCORED {
+        return valid_words.iter().collect();
+    }
+
+    let mut sample: HashSet<&String> = valid_words
+        .choose_multiple(&mut rand::thread_rng(), MAX_GUESSES_SCORED)
+        .collect();
+
+    let valid_set: HashSet<&String> = valid_words.iter().collect();
+    for (a, b) in candidates {
+        if let Some(&w) = valid_set.get(a) {
+            sample.insert(w);
+        }
+        if let Some(&w) = valid_set.get(b) {
+            sample.insert(w);
+        }
+    }
+
+    sample.into_iter().collect()
+}
+
+/// Partition `candidates` by the feedback pattern that `guess` would produce against each pair.
+pub(crate) fn partition_by_pattern(guess: &str, candidates: &[(String, String)]) -> PatternBuckets {
+    let mut buckets: PatternBuckets = HashMap::new();
+    for (a, b) in candidates {
+        let result = simulate_guess(guess, &[a.clone(), b.clone()]);
+        let key = (result.letter_results, result.both_words);
+        buckets.entry(key).or_default().push((a.clone(), b.clone()));
+    }
+    buckets
+}
+
+/// Expected number of candidate pairs remaining after guessing `guess`.
+fn average_remaining(guess: &str, candidates: &[(String, String)]) -> f64 {
+    let total = candidates.len() as f64;
+    partition_by_pattern(guess, candidates)
+        .values()
+        .map(|bucket| {
+            let p = bucket.len() as f64 / total;
+            p * bucket.len() as f64
+        })
+        .sum()
+}
+
+/// Choose the guess from `valid_words` that maximises the expected information (in bits) its
+/// feedback carries about which of `candidates` is the true answer pair, breaking ties in
+/// favour of a guess that is itself still a possible answer.
+pub(crate) fn best_entropy_guess(valid_words: &[String], candidates: &[(String, String)]) -> String {
+    let is_possible_answer = |w: &str| candidates.iter().any(|(a, b)| a == w || b == w);
+
+    guesses_to_score(valid_words, candidates)
+        .into_iter()
+        .max_by(|a, b| {
+            expected_entropy(a, candidates)
+                .partial_cmp(&expected_entropy(b, candidates))
+                .unwrap()
+                .then_with(|| is_possible_answer(a).cmp(&is_possible_answer(b)))
+        })
+        .cloned()
+        .expect("valid words list should never be empty")
+}
+
+/// Expected information, in bits, that guessing `guess` reveals about which candidate pair is
+/// the true answer: `-Σ p_k log2(p_k)` over the feedback-pattern buckets it partitions
+/// `candidates` into.
+fn expected_entropy(guess: &str, candidates: &[(String, String)]) -> f64 {
+    let total = candidates.len() as f64;
+    partition_by_pattern(guess, candidates)
+        .values()
+        .map(|bucket| {
+            let p = bucket.len() as f64 / total;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GameState;
+
+    fn game_with_fixed_answers(answers: Vec<String>) -> GameState {
+        GameState {
+            answers,
+            valid_words: get_valid_words(),
+            guess_count: 0,
+            max_guesses: 6,
+            all_chars: "qwertyuiopasdfghjklzxcvbnm".chars().collect(),
+            found_chars: HashSet::new(),
+            eliminated_chars: HashSet::new(),
+            candidates: builtin_candidate_pairs().clone(),
+            word_bank: get_word_bank(),
+            history: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_filter_consistent_shrinks_candidates_and_keeps_true_pair() {
+        let answers = vec!["arise".to_string(), "count".to_string()];
+        let mut candidates = builtin_candidate_pairs().clone();
+        let before = candidates.len();
+
+        let result = simulate_guess("shunt", &answers);
+        filter_consistent(&mut candidates, "shunt", &result);
+
+        assert!(candidates.len() < before);
+        assert!(candidates.contains(&("arise".to_string(), "count".to_string())));
+    }
+
+    #[test]
+    fn test_constraint_filter_solver_wins_within_max_guesses() {
+        let mut state = game_with_fixed_answers(vec!["arise".to_string(), "count".to_string()]);
+        let mut solver = ConstraintFilterSolver::new();
+
+        while !state.out_of_guesses() && !state.is_won() {
+            solver.make_a_move(&mut state);
+        }
+
+        assert!(state.is_won());
+    }
+
+    #[test]
+    fn test_choose_guess_ties_favour_possible_answer() {
+        let solver = ConstraintFilterSolver {
+            candidates: vec![("arise".to_string(), "count".to_string())],
+            valid_words: vec!["zesty".to_string(), "arise".to_string(), "bingo".to_string()],
+        };
+
+        assert_eq!("arise", solver.choose_guess());
+    }
+
+    #[test]
+    fn test_expected_entropy_handles_repeated_letter_guess() {
+        let candidates = vec![
+            ("arise".to_string(), "count".to_string()),
+            ("audio".to_string(), "stack".to_string()),
+            ("bathe".to_string(), "dough".to_string()),
+        ];
+
+        // "geese" has a repeated 'e'; partition_by_pattern must still produce one letter_result
+        // per letter of "geese" (so one pattern of length 5 per candidate), not silently drop
+        // the repeat or panic on it.
+        let buckets = partition_by_pattern("geese", &candidates);
+        let total: usize = buckets.values().map(|bucket| bucket.len()).sum();
+        assert_eq!(candidates.len(), total);
+        for (pattern, _) in buckets.keys() {
+            assert_eq!(5, pattern.len());
+        }
+
+        assert!(expected_entropy("geese", &candidates) >= 0.0);
+    }
+
+    #[test]
+    fn test_best_entropy_guess_ties_favour_possible_answer() {
+        // With a single remaining candidate pair, every guess produces exactly one feedback
+        // pattern, so every valid word has zero expected entropy -- a total tie.
+        // best_entropy_guess should then prefer the valid word that's itself a candidate.
+        let candidates = vec![("arise".to_string(), "count".to_string())];
+        let valid_words = vec!["zesty".to_string(), "arise".to_string(), "bingo".to_string()];
+
+        assert_eq!("arise", best_entropy_guess(&valid_words, &candidates));
+    }
+}